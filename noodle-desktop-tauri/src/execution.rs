@@ -0,0 +1,327 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
+
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use tauri::{AppHandle, Manager, Runtime};
+use uuid::Uuid;
+
+use crate::sandbox::{self, Jail};
+
+const RUN_STATUS_RUNNING: u8 = 0;
+const RUN_STATUS_CANCELLED: u8 = 1;
+const RUN_STATUS_TIMED_OUT: u8 = 2;
+
+/// Live child processes for in-flight runs, keyed by run id, so
+/// `cancel_execution` can kill one without tearing down the others. The
+/// status flag distinguishes a user-requested cancel from the watchdog
+/// timeout so the eventual `noodle://exit` event reports the real reason.
+static RUNNING: OnceCell<Mutex<HashMap<String, (Arc<Mutex<Child>>, Arc<AtomicU8>)>>> = OnceCell::new();
+
+fn running() -> &'static Mutex<HashMap<String, (Arc<Mutex<Child>>, Arc<AtomicU8>)>> {
+    RUNNING.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[derive(Clone, Serialize)]
+struct LinePayload {
+    run_id: String,
+    line: String,
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ExitReason {
+    Exited,
+    TimedOut,
+    Killed,
+    LimitExceeded,
+}
+
+#[derive(Clone, Serialize)]
+struct ExitPayload {
+    run_id: String,
+    code: Option<i32>,
+    reason: ExitReason,
+}
+
+/// Resolves the bundled `noodle_dev` entry point directory shipped as an app
+/// resource, so execution works from a packaged `.app`/`.msi`/`.AppImage`
+/// rather than only from a checked-out repo.
+fn resolve_noodle_dev_dir<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf, String> {
+    app_handle
+        .path_resolver()
+        .resolve_resource("noodle_dev")
+        .ok_or_else(|| "failed to resolve bundled noodle_dev resource".to_string())
+}
+
+/// Resolves the bundled Python interpreter's absolute path as an app
+/// resource. A raw `std::process::Command` is spawned against this path
+/// (rather than going through the sidecar command API) so the run can be
+/// given `pre_exec` rlimits on Unix; see `sandbox::apply_resource_limits`.
+fn resolve_python_binary<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf, String> {
+    let exe_name = if cfg!(windows) { "python.exe" } else { "python" };
+    app_handle
+        .path_resolver()
+        .resolve_resource(format!("binaries/{}", exe_name))
+        .ok_or_else(|| "failed to resolve bundled python binary".to_string())
+}
+
+fn write_script_into_jail(jail: &Jail, code: &str) -> Result<String, String> {
+    let script_path = jail.dir.join("script.nl");
+    std::fs::write(&script_path, code).map_err(|e| e.to_string())?;
+    script_path
+        .to_str()
+        .map(String::from)
+        .ok_or_else(|| "Invalid temp path".to_string())
+}
+
+/// Whether `status` indicates the child was killed by a signal rather than
+/// exiting normally. On Unix this is how a `pre_exec` rlimit (`SIGXCPU`/
+/// `SIGKILL` from the CPU cap) shows up; `apply_resource_limits` is a no-op
+/// on other platforms, so there's no limit there to report as exceeded.
+#[cfg(unix)]
+fn died_by_signal(status: &ExitStatus) -> bool {
+    status.signal().is_some()
+}
+
+#[cfg(not(unix))]
+fn died_by_signal(_status: &ExitStatus) -> bool {
+    false
+}
+
+/// Builds the child command for a python run: resolved interpreter path,
+/// jailed working directory, a minimal allowlisted environment (fully
+/// replacing whatever the host process's environment is), and rlimits.
+fn build_python_command<R: Runtime>(
+    app_handle: &AppHandle<R>,
+    jail: &Jail,
+    extra_env: &[(&str, String)],
+    args: &[String],
+) -> Result<Command, String> {
+    let python_path = resolve_python_binary(app_handle)?;
+
+    let mut command = Command::new(python_path);
+    command
+        .current_dir(&jail.dir)
+        .args(args)
+        .env_clear()
+        .envs(sandbox::minimal_env(extra_env))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    Ok(sandbox::apply_resource_limits(command))
+}
+
+/// Spawns `command` inside `jail` under `run_id`, and forwards its
+/// stdout/stderr/exit as `noodle://stdout`, `noodle://stderr` and
+/// `noodle://exit` events tagged with that run id. Returns immediately
+/// rather than waiting for the process to finish. The run is killed if it
+/// outlives `sandbox::WALL_CLOCK_TIMEOUT`.
+fn spawn_and_stream<R: Runtime>(
+    app_handle: AppHandle<R>,
+    run_id: String,
+    mut command: Command,
+    jail: Jail,
+) -> Result<String, String> {
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to spawn process: {}", e))?;
+    let stdout = child.stdout.take().ok_or("Failed to capture stdout")?;
+    let stderr = child.stderr.take().ok_or("Failed to capture stderr")?;
+
+    let status = Arc::new(AtomicU8::new(RUN_STATUS_RUNNING));
+    let child = Arc::new(Mutex::new(child));
+
+    running()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .insert(run_id.clone(), (child.clone(), status.clone()));
+
+    let stdout_app = app_handle.clone();
+    let stdout_run_id = run_id.clone();
+    let stdout_handle = thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().flatten() {
+            let _ = stdout_app.emit_all(
+                "noodle://stdout",
+                LinePayload {
+                    run_id: stdout_run_id.clone(),
+                    line,
+                },
+            );
+        }
+    });
+
+    let stderr_app = app_handle.clone();
+    let stderr_run_id = run_id.clone();
+    let stderr_handle = thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().flatten() {
+            let _ = stderr_app.emit_all(
+                "noodle://stderr",
+                LinePayload {
+                    run_id: stderr_run_id.clone(),
+                    line,
+                },
+            );
+        }
+    });
+
+    let watchdog_child = child.clone();
+    let watchdog_status = status.clone();
+    let watchdog_run_id = run_id.clone();
+    thread::spawn(move || {
+        thread::sleep(sandbox::WALL_CLOCK_TIMEOUT);
+        let still_running = running()
+            .lock()
+            .ok()
+            .map(|m| m.contains_key(&watchdog_run_id))
+            .unwrap_or(false);
+        if still_running {
+            watchdog_status.store(RUN_STATUS_TIMED_OUT, Ordering::SeqCst);
+            if let Ok(mut child) = watchdog_child.lock() {
+                let _ = child.kill();
+            }
+        }
+    });
+
+    let completion_app = app_handle;
+    let completion_run_id = run_id.clone();
+    thread::spawn(move || {
+        let _jail = jail;
+        let _ = stdout_handle.join();
+        let _ = stderr_handle.join();
+
+        let exit_status = child.lock().ok().and_then(|mut c| c.wait().ok());
+        let exit_code = exit_status.as_ref().and_then(ExitStatus::code);
+
+        let reason = match status.load(Ordering::SeqCst) {
+            RUN_STATUS_CANCELLED => ExitReason::Killed,
+            RUN_STATUS_TIMED_OUT => ExitReason::TimedOut,
+            _ if exit_status.as_ref().is_some_and(died_by_signal) => ExitReason::LimitExceeded,
+            _ => ExitReason::Exited,
+        };
+
+        let _ = completion_app.emit_all(
+            "noodle://exit",
+            ExitPayload {
+                run_id: completion_run_id.clone(),
+                code: exit_code,
+                reason,
+            },
+        );
+
+        running().lock().ok().map(|mut m| m.remove(&completion_run_id));
+    });
+
+    Ok(run_id)
+}
+
+#[tauri::command]
+pub async fn execute_noodle<R: Runtime>(
+    app_handle: AppHandle<R>,
+    code: String,
+) -> Result<String, String> {
+    crate::permissions::require("allow-execute-noodle")?;
+
+    let run_id = Uuid::new_v4().to_string();
+    let jail = Jail::create(&run_id)?;
+    let script_path = write_script_into_jail(&jail, &code)?;
+    let noodle_dev_dir = resolve_noodle_dev_dir(&app_handle)?;
+
+    let command = build_python_command(
+        &app_handle,
+        &jail,
+        &[("PYTHONPATH", noodle_dev_dir.to_string_lossy().to_string())],
+        &[
+            "-m".to_string(),
+            "noodle_dev.core_entry_point".to_string(),
+            script_path,
+        ],
+    )?;
+
+    spawn_and_stream(app_handle, run_id, command, jail)
+}
+
+#[tauri::command]
+pub async fn execute_python_file<R: Runtime>(
+    app_handle: AppHandle<R>,
+    file_path: String,
+) -> Result<String, String> {
+    crate::permissions::require("allow-execute-python")?;
+
+    let file_path = crate::scope::check_path_allowed(std::path::Path::new(&file_path))?;
+
+    let run_id = Uuid::new_v4().to_string();
+    let jail = Jail::create(&run_id)?;
+
+    let command = build_python_command(
+        &app_handle,
+        &jail,
+        &[],
+        &[file_path.to_str().ok_or("Invalid file path")?.to_string()],
+    )?;
+
+    spawn_and_stream(app_handle, run_id, command, jail)
+}
+
+#[tauri::command]
+pub async fn execute_noodle_file<R: Runtime>(
+    app_handle: AppHandle<R>,
+    file_path: String,
+) -> Result<String, String> {
+    crate::permissions::require("allow-execute-noodle")?;
+
+    let file_path = crate::scope::check_path_allowed(std::path::Path::new(&file_path))?;
+
+    let code = std::fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read Noodle file: {}", e))?;
+
+    let run_id = Uuid::new_v4().to_string();
+    let jail = Jail::create(&run_id)?;
+    let script_path = write_script_into_jail(&jail, &code)?;
+    let noodle_dev_dir = resolve_noodle_dev_dir(&app_handle)?;
+
+    let command = build_python_command(
+        &app_handle,
+        &jail,
+        &[("PYTHONPATH", noodle_dev_dir.to_string_lossy().to_string())],
+        &[
+            "-m".to_string(),
+            "noodle_dev.core_entry_point".to_string(),
+            script_path,
+        ],
+    )?;
+
+    spawn_and_stream(app_handle, run_id, command, jail)
+}
+
+#[tauri::command]
+pub async fn cancel_execution(run_id: String) -> Result<(), String> {
+    if crate::permissions::require("allow-execute-noodle").is_err()
+        && crate::permissions::require("allow-execute-python").is_err()
+    {
+        return Err("capability 'allow-execute-noodle' or 'allow-execute-python' is not enabled for this app".to_string());
+    }
+
+    let entry = running().lock().map_err(|e| e.to_string())?.remove(&run_id);
+
+    match entry {
+        Some((child, status)) => {
+            status.store(RUN_STATUS_CANCELLED, Ordering::SeqCst);
+            child
+                .lock()
+                .map_err(|e| e.to_string())?
+                .kill()
+                .map_err(|e| e.to_string())
+        }
+        None => Err(format!("no running execution with id {}", run_id)),
+    }
+}
@@ -0,0 +1,33 @@
+use once_cell::sync::OnceCell;
+use std::collections::HashSet;
+
+/// Capability identifiers enabled for this plugin instance, read from
+/// `plugins.secure_store.enabledCapabilities` in `tauri.conf.json` and
+/// matching the identifiers declared in `permissions/default.toml`. Nothing
+/// is enabled until configured, so a window gets no commands by default.
+static ENABLED: OnceCell<HashSet<String>> = OnceCell::new();
+
+pub fn configure(capabilities: &[String]) -> Result<(), String> {
+    ENABLED
+        .set(capabilities.iter().cloned().collect())
+        .map_err(|_| "capabilities already configured".to_string())
+}
+
+fn enabled() -> &'static HashSet<String> {
+    ENABLED.get_or_init(HashSet::new)
+}
+
+/// Rejects the call unless `capability` was enabled for this app. This is
+/// the runtime enforcement side of the capability manifest: the plugin
+/// targets Tauri v1, which has no ACL engine of its own to read
+/// `permissions/default.toml`, so each command checks this explicitly.
+pub fn require(capability: &str) -> Result<(), String> {
+    if enabled().contains(capability) {
+        Ok(())
+    } else {
+        Err(format!(
+            "capability '{}' is not enabled for this app",
+            capability
+        ))
+    }
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use std::process::Command;
+use std::time::Duration;
+
+/// Wall-clock budget for a single run before it's killed as timed out.
+pub const WALL_CLOCK_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[cfg(unix)]
+const CPU_TIME_LIMIT_SECS: u64 = 30;
+#[cfg(unix)]
+const ADDRESS_SPACE_LIMIT_BYTES: u64 = 512 * 1024 * 1024;
+
+/// A unique, per-run scratch directory so concurrent executions never share
+/// a working directory or clobber each other's script file. Removed
+/// automatically when dropped.
+pub struct Jail {
+    pub dir: PathBuf,
+}
+
+impl Jail {
+    pub fn create(run_id: &str) -> Result<Self, String> {
+        let dir = std::env::temp_dir().join(format!("noodle-run-{}", run_id));
+        std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+        restrict_to_owner(&dir)?;
+        Ok(Self { dir })
+    }
+}
+
+/// Locks the jail directory down to the owner so other local users can't
+/// read the script being executed while the run is live.
+#[cfg(unix)]
+fn restrict_to_owner(dir: &std::path::Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700)).map_err(|e| e.to_string())
+}
+
+#[cfg(not(unix))]
+fn restrict_to_owner(_dir: &std::path::Path) -> Result<(), String> {
+    Ok(())
+}
+
+impl Drop for Jail {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Caps the child's CPU time and address space on Unix so a runaway or
+/// malicious script can't consume unbounded host resources. No-op on
+/// platforms without rlimits.
+///
+/// This has to run as a `pre_exec` hook on a raw `std::process::Command` —
+/// the sidecar wrapper (`tauri::api::process::Command`) has no equivalent
+/// hook, so there is no way to apply rlimits to a sidecar-spawned child.
+#[cfg(unix)]
+pub fn apply_resource_limits(mut command: Command) -> Command {
+    use std::os::unix::process::CommandExt;
+
+    unsafe {
+        command.pre_exec(|| {
+            rlimit::setrlimit(rlimit::Resource::CPU, CPU_TIME_LIMIT_SECS, CPU_TIME_LIMIT_SECS)?;
+            rlimit::setrlimit(
+                rlimit::Resource::AS,
+                ADDRESS_SPACE_LIMIT_BYTES,
+                ADDRESS_SPACE_LIMIT_BYTES,
+            )?;
+            Ok(())
+        });
+    }
+
+    command
+}
+
+#[cfg(not(unix))]
+pub fn apply_resource_limits(command: Command) -> Command {
+    command
+}
+
+/// Variables every platform's process loader/CRT needs to start at all, on
+/// top of whichever interpreter-specific ones a call site adds.
+#[cfg(windows)]
+const REQUIRED_VARS: &[&str] = &["PATH", "SystemRoot", "TEMP", "TMP", "USERPROFILE"];
+#[cfg(not(windows))]
+const REQUIRED_VARS: &[&str] = &["PATH", "HOME", "TMPDIR"];
+
+/// Strips the child's environment down to the handful of variables it
+/// actually needs instead of inheriting the host process's environment.
+pub fn minimal_env(extra: &[(&str, String)]) -> Vec<(String, String)> {
+    let mut env: Vec<(String, String)> = REQUIRED_VARS
+        .iter()
+        .filter_map(|name| std::env::var(name).ok().map(|value| (name.to_string(), value)))
+        .collect();
+    env.extend(extra.iter().map(|(k, v)| (k.to_string(), v.clone())));
+    env
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_required_vars_and_extras_are_present() {
+        let env = minimal_env(&[("PYTHONPATH", "/bundled/noodle_dev".to_string())]);
+        let keys: Vec<&str> = env.iter().map(|(k, _)| k.as_str()).collect();
+
+        assert!(keys.contains(&"PYTHONPATH"));
+        for key in &keys {
+            assert!(
+                REQUIRED_VARS.contains(key) || *key == "PYTHONPATH",
+                "unexpected env var leaked into the allowlist: {}",
+                key
+            );
+        }
+        assert!(!keys.contains(&"SOME_UNRELATED_HOST_VAR"));
+    }
+
+    #[test]
+    fn extras_override_nothing_but_are_appended() {
+        let without_extra = minimal_env(&[]);
+        let with_extra = minimal_env(&[("PYTHONPATH", "/x".to_string())]);
+
+        assert_eq!(with_extra.len(), without_extra.len() + 1);
+    }
+}
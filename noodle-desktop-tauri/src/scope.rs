@@ -0,0 +1,105 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use once_cell::sync::OnceCell;
+use std::path::{Path, PathBuf};
+
+/// Path-scope glob patterns this plugin instance was configured with, read
+/// from the `plugins.secure_store.executionScope` array in `tauri.conf.json`.
+/// Until configured, the scope is empty and every path is rejected.
+static EXECUTION_SCOPE: OnceCell<GlobSet> = OnceCell::new();
+
+fn build_scope(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).map_err(|e| format!("invalid scope glob '{}': {}", pattern, e))?);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+pub fn configure(patterns: &[String]) -> Result<(), String> {
+    let set = build_scope(patterns)?;
+
+    EXECUTION_SCOPE
+        .set(set)
+        .map_err(|_| "execution scope already configured".to_string())
+}
+
+fn scope() -> &'static GlobSet {
+    EXECUTION_SCOPE.get_or_init(GlobSet::empty)
+}
+
+/// Rejects `path` unless it falls inside the configured execution scope,
+/// then canonicalizes it, so `execute_python_file`/`execute_noodle_file`
+/// can't be pointed at arbitrary files on disk.
+///
+/// The scope match runs against the raw path *before* touching the
+/// filesystem: `Path::canonicalize` fails for paths that don't exist, so
+/// canonicalizing first would let a caller distinguish "doesn't exist" from
+/// "outside the allowed scope" for any path on disk, regardless of scope —
+/// an existence oracle that defeats the point of the scope check. Matching
+/// the canonical form too, after, catches a path that only looks in-scope
+/// before resolution (e.g. a symlink or `..` that escapes it).
+pub fn check_path_allowed(path: &Path) -> Result<PathBuf, String> {
+    if !scope().is_match(path) {
+        return Err(format!(
+            "path '{}' is outside the allowed execution scope",
+            path.display()
+        ));
+    }
+
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve path: {}", e))?;
+
+    if scope().is_match(&canonical) {
+        Ok(canonical)
+    } else {
+        Err(format!(
+            "path '{}' is outside the allowed execution scope",
+            canonical.display()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_paths_under_an_allowed_glob() {
+        let dir = std::env::temp_dir().join("noodle-scope-test-allowed");
+        std::fs::create_dir_all(&dir).unwrap();
+        let allowed = dir.join("script.nl");
+        std::fs::write(&allowed, "").unwrap();
+
+        let pattern = format!("{}/**", dir.to_string_lossy());
+        let scope = build_scope(&[pattern]).unwrap();
+
+        assert!(scope.is_match(allowed.canonicalize().unwrap()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_paths_outside_every_allowed_glob() {
+        let allowed_dir = std::env::temp_dir().join("noodle-scope-test-rejected-allowed");
+        let outside_dir = std::env::temp_dir().join("noodle-scope-test-rejected-outside");
+        std::fs::create_dir_all(&allowed_dir).unwrap();
+        std::fs::create_dir_all(&outside_dir).unwrap();
+        let outside_file = outside_dir.join("script.nl");
+        std::fs::write(&outside_file, "").unwrap();
+
+        let pattern = format!("{}/**", allowed_dir.to_string_lossy());
+        let scope = build_scope(&[pattern]).unwrap();
+
+        assert!(!scope.is_match(outside_file.canonicalize().unwrap()));
+
+        std::fs::remove_dir_all(&allowed_dir).unwrap();
+        std::fs::remove_dir_all(&outside_dir).unwrap();
+    }
+
+    #[test]
+    fn empty_scope_rejects_everything() {
+        let scope = build_scope(&[]).unwrap();
+        assert!(!scope.is_match(std::env::temp_dir()));
+    }
+}
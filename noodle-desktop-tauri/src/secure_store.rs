@@ -0,0 +1,205 @@
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use once_cell::sync::OnceCell;
+use rand::RngCore;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager, Runtime};
+
+const STORE_FILE: &str = "secure.store";
+const SALT_FILE: &str = "secure.salt";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Holds the key derived from the master passphrase for the lifetime of an
+/// unlocked session. `None` means the store is locked.
+static SESSION: OnceCell<Mutex<Option<[u8; 32]>>> = OnceCell::new();
+
+fn session() -> &'static Mutex<Option<[u8; 32]>> {
+    SESSION.get_or_init(|| Mutex::new(None))
+}
+
+/// Resolves the app's data directory, creating it if needed, so the salt and
+/// store files live somewhere stable across launches instead of wherever the
+/// process happened to be started from. A packaged app's current working
+/// directory isn't guaranteed across launches (Finder/Dock/systemd vs. a
+/// terminal in the repo); see `execution.rs`'s `resolve_noodle_dev_dir` for
+/// the same reasoning applied to resource paths.
+fn resolve_data_dir<R: Runtime>(app_handle: &AppHandle<R>) -> Result<PathBuf, String> {
+    let dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "failed to resolve app data directory".to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+fn load_or_create_salt(salt_path: &Path) -> Result<[u8; SALT_LEN], String> {
+    if let Ok(existing) = std::fs::read(salt_path) {
+        if existing.len() == SALT_LEN {
+            let mut salt = [0u8; SALT_LEN];
+            salt.copy_from_slice(&existing);
+            return Ok(salt);
+        }
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    std::fs::write(salt_path, &salt).map_err(|e| e.to_string())?;
+    Ok(salt)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+    Ok(key)
+}
+
+fn require_session_key() -> Result<[u8; 32], String> {
+    session()
+        .lock()
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "store is locked: call unlock_store first".to_string())
+}
+
+/// Encrypts `plaintext`, returning `base64(nonce ‖ ciphertext ‖ tag)`. The
+/// salt isn't part of the payload: it's already persisted once in
+/// `SALT_FILE`, and `unlock_store` reads it from there to derive the session
+/// key, so embedding another copy per value would be pure waste.
+fn encrypt(key: &[u8; 32], plaintext: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    let mut payload = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(&nonce_bytes);
+    payload.extend_from_slice(&ciphertext);
+
+    Ok(BASE64.encode(payload))
+}
+
+fn decrypt(key: &[u8; 32], encoded: &str) -> Result<String, String> {
+    let payload = BASE64
+        .decode(encoded)
+        .map_err(|_| "decryption failed / wrong passphrase".to_string())?;
+
+    if payload.len() < NONCE_LEN {
+        return Err("decryption failed / wrong passphrase".to_string());
+    }
+
+    let nonce_bytes = &payload[..NONCE_LEN];
+    let ciphertext = &payload[NONCE_LEN..];
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    let plaintext = cipher
+        .decrypt(nonce, ciphertext)
+        .map_err(|_| "decryption failed / wrong passphrase".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|_| "decryption failed / wrong passphrase".to_string())
+}
+
+#[tauri::command]
+pub async fn unlock_store<R: Runtime>(
+    app_handle: AppHandle<R>,
+    passphrase: String,
+) -> Result<(), String> {
+    crate::permissions::require("allow-secure-write")?;
+    let data_dir = resolve_data_dir(&app_handle)?;
+    let salt = load_or_create_salt(&data_dir.join(SALT_FILE))?;
+    let key = derive_key(&passphrase, &salt)?;
+    *session().lock().map_err(|e| e.to_string())? = Some(key);
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn lock_store() -> Result<(), String> {
+    crate::permissions::require("allow-secure-write")?;
+    *session().lock().map_err(|e| e.to_string())? = None;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn store_secure_value<R: Runtime>(
+    app_handle: AppHandle<R>,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    crate::permissions::require("allow-secure-write")?;
+    let session_key = require_session_key()?;
+    let encoded = encrypt(&session_key, &value)?;
+
+    let store_path = resolve_data_dir(&app_handle)?.join(STORE_FILE);
+    let mut store = tauri_plugin_store::StoreBuilder::new(store_path)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    store
+        .insert(key, encoded)
+        .save()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_secure_value<R: Runtime>(
+    app_handle: AppHandle<R>,
+    key: String,
+) -> Result<Option<String>, String> {
+    crate::permissions::require("allow-secure-read")?;
+    let session_key = require_session_key()?;
+
+    let store_path = resolve_data_dir(&app_handle)?.join(STORE_FILE);
+    let store = tauri_plugin_store::StoreBuilder::new(store_path)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    match store.get(key).cloned() {
+        Some(encoded) => Ok(Some(decrypt(&session_key, &encoded)?)),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let key = [7u8; 32];
+        let encoded = encrypt(&key, "hello secure world").unwrap();
+
+        assert_eq!(decrypt(&key, &encoded).unwrap(), "hello secure world");
+    }
+
+    #[test]
+    fn decrypt_with_wrong_key_fails() {
+        let key = [1u8; 32];
+        let other_key = [2u8; 32];
+        let encoded = encrypt(&key, "top secret").unwrap();
+
+        let err = decrypt(&other_key, &encoded).unwrap_err();
+        assert_eq!(err, "decryption failed / wrong passphrase");
+    }
+
+    #[test]
+    fn decrypt_rejects_truncated_payload() {
+        let key = [3u8; 32];
+        let err = decrypt(&key, &BASE64.encode([0u8; NONCE_LEN - 1])).unwrap_err();
+        assert_eq!(err, "decryption failed / wrong passphrase");
+    }
+}